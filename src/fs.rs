@@ -0,0 +1,125 @@
+//! Locating and persisting the `o_o.ini` save file on disk.
+//!
+//! The Sharecart1000 spec expects the file to live at `dat/o_o.ini`,
+//! relative to wherever the game executable happens to be running from.
+//! This module knows how to find that file (or make a fresh one) so that a
+//! game doesn't have to reimplement the search-and-create dance itself.
+
+use crate::Sharecart;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The directory that the save file lives inside of, per the spec.
+pub const SHARECART_DIR_NAME: &str = "dat";
+
+/// The file name of the save file within [`SHARECART_DIR_NAME`].
+pub const SHARECART_FILE_NAME: &str = "o_o.ini";
+
+/// Finds the `dat/o_o.ini` file, creating a default one if necessary.
+///
+/// The search starts at the current executable's directory (falling back to
+/// the current directory if that can't be determined) and walks upward
+/// through parent directories looking for an existing `dat/o_o.ini`. If none
+/// is found anywhere above the starting point, a `dat` directory and a
+/// default `o_o.ini` are created next to the starting point instead, and
+/// that new path is returned.
+///
+/// ```rust,no_run
+/// use sharecart1000::fs::locate_sharecart_ini;
+///
+/// let path = locate_sharecart_ini().unwrap();
+/// println!("using save file at {}", path.display());
+/// ```
+pub fn locate_sharecart_ini() -> io::Result<PathBuf> {
+  let start = env::current_exe()
+    .ok()
+    .and_then(|p| p.parent().map(Path::to_path_buf))
+    .or_else(|| env::current_dir().ok())
+    .unwrap_or_else(|| PathBuf::from("."));
+
+  locate_from(&start)
+}
+
+/// The walk-upward-then-create-a-default logic behind [`locate_sharecart_ini`],
+/// split out so it can be driven from an arbitrary starting directory
+/// instead of always the current executable's, which makes it testable.
+fn locate_from(start: &Path) -> io::Result<PathBuf> {
+  let mut dir = start;
+  loop {
+    let candidate = dir.join(SHARECART_DIR_NAME).join(SHARECART_FILE_NAME);
+    if candidate.is_file() {
+      return Ok(candidate);
+    }
+    match dir.parent() {
+      Some(parent) => dir = parent,
+      None => break,
+    }
+  }
+
+  let dat_dir = start.join(SHARECART_DIR_NAME);
+  fs::create_dir_all(&dat_dir)?;
+  let ini_path = dat_dir.join(SHARECART_FILE_NAME);
+  if !ini_path.is_file() {
+    fs::write(&ini_path, Sharecart::default().to_string())?;
+  }
+  Ok(ini_path)
+}
+
+/// Makes (and empties out) a scratch directory under the system temp
+/// directory, unique to this test and this process.
+#[cfg(test)]
+fn scratch_dir(name: &str) -> PathBuf {
+  let dir = env::temp_dir().join(format!("sharecart1000_test_{}_{}", std::process::id(), name));
+  let _ = fs::remove_dir_all(&dir);
+  fs::create_dir_all(&dir).unwrap();
+  dir
+}
+
+#[test]
+fn test_locate_from_creates_a_default() {
+  let start = scratch_dir("locate_from_creates_a_default");
+
+  let ini_path = locate_from(&start).unwrap();
+
+  assert_eq!(ini_path, start.join(SHARECART_DIR_NAME).join(SHARECART_FILE_NAME));
+  assert_eq!(fs::read_to_string(&ini_path).unwrap(), Sharecart::default().to_string());
+
+  fs::remove_dir_all(&start).unwrap();
+}
+
+#[test]
+fn test_locate_from_finds_an_ancestors_dat_dir() {
+  let start = scratch_dir("locate_from_finds_an_ancestor");
+  let dat_dir = start.join(SHARECART_DIR_NAME);
+  fs::create_dir_all(&dat_dir).unwrap();
+  let mut sc = Sharecart::default();
+  sc.map_x = 42;
+  fs::write(dat_dir.join(SHARECART_FILE_NAME), sc.to_string()).unwrap();
+
+  let nested = start.join("a").join("b").join("c");
+  fs::create_dir_all(&nested).unwrap();
+
+  let ini_path = locate_from(&nested).unwrap();
+  assert_eq!(ini_path, dat_dir.join(SHARECART_FILE_NAME));
+
+  fs::remove_dir_all(&start).unwrap();
+}
+
+#[test]
+fn test_load_and_save_round_trip() {
+  let dir = scratch_dir("load_and_save_round_trip");
+  let path = dir.join(SHARECART_FILE_NAME);
+
+  assert_eq!(Sharecart::load_from_path(&path).unwrap(), Sharecart::default());
+
+  let mut sc = Sharecart::default();
+  sc.map_x = 73;
+  sc.player_name = "Tester".into();
+  sc.save_to_path(&path).unwrap();
+
+  assert_eq!(Sharecart::load_from_path(&path).unwrap(), sc);
+
+  fs::remove_dir_all(&dir).unwrap();
+}
@@ -9,9 +9,187 @@
 #![forbid(missing_debug_implementations)]
 #![forbid(missing_docs)]
 #![forbid(unsafe_code)]
+// `from_str`/`to_string` are inherent methods (not `FromStr`/`Display`) so
+// that they can work on `S: AsRef<str>` and skip the `Result`/`Error` dance;
+// every doc example and downstream user already depends on this shape.
+#![allow(clippy::should_implement_trait, clippy::inherent_to_string)]
+// The examples and tests build a `Sharecart` by starting from `default()`
+// and mutating one field at a time, to show each field changing in
+// isolation; that's the point, not an oversight.
+#![allow(clippy::field_reassign_with_default)]
 
+extern crate bstr;
 extern crate ini;
 
+pub mod fs;
+
+use bstr::{BString, ByteSlice};
+use std::borrow::Cow;
+use std::io;
+use std::path::Path;
+
+/// Truncates `raw` to at most `max_bytes` bytes, stopping on a whole
+/// grapheme-cluster boundary rather than slicing through the middle of one.
+///
+/// Unlike slicing a `&str`, this works directly on bytes: `bstr`'s grapheme
+/// segmentation treats a run of invalid UTF-8 as a cluster of its own, so
+/// it's kept or dropped whole right alongside real grapheme clusters, and
+/// the *original* bytes of that span are what get copied into the result
+/// (not a `'\u{FFFD}'` stand-in). That's what lets this double as both the
+/// "don't split a cluster" truncation and the "keep non-UTF-8 legacy bytes
+/// intact" truncation, for whatever `raw` turns out to contain.
+///
+/// Any grapheme that is just `'\r'`, `'\n'`, or a `"\r\n"` pair (which is a
+/// single grapheme cluster) is dropped entirely rather than counted against
+/// the budget.
+fn truncate_player_name(raw: &[u8], max_bytes: usize) -> BString {
+  let mut kept = Vec::with_capacity(max_bytes.min(raw.len()));
+  let mut used = 0usize;
+  for (start, end, grapheme) in raw.grapheme_indices() {
+    if grapheme == "\r" || grapheme == "\n" || grapheme == "\r\n" {
+      continue;
+    }
+    let len = end - start;
+    if used + len > max_bytes {
+      break;
+    }
+    kept.extend_from_slice(&raw[start..end]);
+    used += len;
+  }
+  BString::from(kept)
+}
+
+/// Controls how invalid UTF-8 in a raw `PlayerName` value gets turned into
+/// a plain `String`, for use with [`Sharecart::from_bytes_with`],
+/// [`Sharecart::from_str_with`], [`Sharecart::to_string_with`], and
+/// [`Sharecart::player_name_lossy_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LossyPolicy {
+  /// Invalid bytes are silently dropped. This is what `to_string` does.
+  Strip,
+  /// Each maximal invalid UTF-8 sequence becomes a single `'\u{FFFD}'`, the
+  /// same as `String::from_utf8_lossy`.
+  Replace,
+  /// Any invalid byte is rejected, turning the whole call into an error.
+  Reject,
+}
+
+/// The `PlayerName` value contained invalid UTF-8, and
+/// [`LossyPolicy::Reject`] was in effect.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidPlayerNameBytes;
+
+/// One problem found while parsing a `Sharecart` with
+/// [`Sharecart::from_str_strict`].
+///
+/// `from_str` handles every one of these the same way it always has
+/// (defaulting the field, wrapping the value, truncating the name); this
+/// just names what happened so that stricter callers can notice.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParseIssue {
+  /// A `MapX`/`MapY` value parsed fine but didn't fit in 10 bits, so it was
+  /// wrapped with `% 1024`.
+  MapCoordOutOfRange {
+    /// The `ini` key, e.g. `"MapX"`.
+    field: &'static str,
+    /// The raw text that was in the file.
+    raw: String,
+    /// The value actually stored, after wrapping.
+    wrapped: u16,
+  },
+  /// A numeric field's text couldn't be parsed as a `u16` at all, so it
+  /// defaulted to 0.
+  IntParseFailed {
+    /// The `ini` key, e.g. `"Misc0"`.
+    field: &'static str,
+    /// The raw text that failed to parse.
+    raw: String,
+  },
+  /// `PlayerName` was longer than the 1024 byte budget, so it was truncated.
+  NameTruncated {
+    /// The byte length of the name as it appeared in the file.
+    original_len: usize,
+    /// The byte length of the name that was actually kept.
+    kept_len: usize,
+  },
+  /// A key in the `[Main]` section wasn't recognized, and was ignored.
+  UnknownKey(String),
+  /// The text couldn't be parsed as `ini` at all (unbalanced sections, a
+  /// stray `=`-less line, etc.), so every field defaulted.
+  IniParseFailed,
+  /// The text parsed as `ini` fine, but had no `[Main]` (or `[main]`)
+  /// section, so every field defaulted.
+  MissingMainSection,
+}
+
+/// Parses `raw` as a `u16` into `*slot`, recording an issue on failure.
+fn parse_u16_field(slot: &mut u16, field: &'static str, raw: &str, issues: &mut Vec<ParseIssue>) {
+  match raw.parse::<u16>() {
+    Ok(value) => *slot = value,
+    Err(_) => issues.push(ParseIssue::IntParseFailed { field, raw: raw.to_string() }),
+  }
+}
+
+/// Parses `raw` as a `u16` into `*slot`, wrapping it to 10 bits and recording
+/// an issue if it didn't already fit, or if it failed to parse at all.
+fn parse_map_coord(slot: &mut u16, field: &'static str, raw: &str, issues: &mut Vec<ParseIssue>) {
+  match raw.parse::<u16>() {
+    Ok(value) => {
+      let wrapped = value % 1024;
+      if wrapped != value {
+        issues.push(ParseIssue::MapCoordOutOfRange { field, raw: raw.to_string(), wrapped });
+      }
+      *slot = wrapped;
+    }
+    Err(_) => issues.push(ParseIssue::IntParseFailed { field, raw: raw.to_string() }),
+  }
+}
+
+/// Decodes `raw` into a `String` according to `policy`, walking the valid and
+/// invalid runs of `raw` separately rather than lossily decoding the whole
+/// thing up front and then hunting for replacement characters afterward.
+fn decode_player_name_with_policy(raw: &[u8], policy: LossyPolicy) -> Result<String, InvalidPlayerNameBytes> {
+  let mut out = String::with_capacity(raw.len());
+  for chunk in raw.utf8_chunks() {
+    out.push_str(chunk.valid());
+    if !chunk.invalid().is_empty() {
+      match policy {
+        LossyPolicy::Strip => {}
+        LossyPolicy::Replace => out.push('\u{FFFD}'),
+        LossyPolicy::Reject => return Err(InvalidPlayerNameBytes),
+      }
+    }
+  }
+  Ok(out)
+}
+
+/// Finds the value of the first `PlayerName=` line in `buf` (the key match
+/// is case-insensitive, matching `from_str`), and returns its raw bytes,
+/// trimmed of leading/trailing ASCII whitespace and the trailing
+/// `'\r'`/`'\n'`, the same as `ini`'s properties are.
+///
+/// This exists so that a `PlayerName` value holding bytes that aren't valid
+/// UTF-8 can still be recovered byte-for-byte from a raw `o_o.ini` file, even
+/// though every other field goes through `ini`, which requires a valid
+/// `&str` for the whole document. Matching `ini`'s own whitespace handling
+/// here (rather than taking the key/value completely verbatim) keeps
+/// `PlayerName = Bob`-style spacing working the same as it does for every
+/// other field.
+fn extract_raw_player_name(buf: &[u8]) -> Option<&[u8]> {
+  for mut line in buf.split(|&b| b == b'\n') {
+    if line.last() == Some(&b'\r') {
+      line = &line[..line.len() - 1];
+    }
+    if let Some(eq) = line.iter().position(|&b| b == b'=') {
+      let key = line[..eq].trim_ascii();
+      if key.eq_ignore_ascii_case(b"playername") {
+        return Some(line[eq + 1..].trim_ascii());
+      }
+    }
+  }
+  None
+}
+
 /// This is your Sharecart data, in a rusty form.
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Sharecart {
@@ -37,18 +215,23 @@ pub struct Sharecart {
 
   /// The player's name, or something like it.
   ///
-  /// The definition of "1023chars" is slightly fuzzy when you get into the fact
-  /// that there's multi-byte characters, but that some languages assume all
-  /// chars are 1 byte. While we're working with it in memory, we just act like
-  /// it's a normal `String` value. If you're just using this field for 1,023 or
-  /// fewer ASCII characters (without line endings), you'll be totally fine.
-  /// Otherwise there's some edge cases to worry about.
+  /// Stored as the exact bytes from the save file, since many legacy
+  /// Sharecart-consuming games treat `PlayerName` as raw single-byte
+  /// (Latin-1-ish) text rather than UTF-8, and a lossily-decoded `String`
+  /// can't round-trip them byte-for-byte. Use
+  /// [`Sharecart::player_name_lossy`] for a `&str`-like view of this field,
+  /// or [`Sharecart::player_name_lossy_with`] to control how invalid UTF-8
+  /// gets handled.
   ///
-  /// * Saving: Takes the first 1023 _bytes_, then lossy re-parses the bytes as
-  ///   chars and filters out any `'\u{0FFFD}'`, `'\r'`, and `'\n'`.
-  /// * Loading: Performs a similar contortion, where the first 1023 bytes are
-  ///   taken, lossy parsed for utf8, filtered, and then that result is kept.
-  pub player_name: String,
+  /// * Saving: Keeps as many whole grapheme clusters as fit within a 1023
+  ///   byte budget, dropping any `'\r'`/`'\n'` byte along the way. `bstr`'s
+  ///   grapheme segmentation treats a run of invalid UTF-8 as a cluster of
+  ///   its own, so a cluster is never split: an accented letter, emoji, or
+  ///   raw non-UTF-8 run near the limit is either kept whole or dropped
+  ///   whole.
+  /// * Loading: Performs the same grapheme-aware truncation, against a 1024
+  ///   byte budget.
+  pub player_name: BString,
 
   /// The eight switches.
   ///
@@ -85,7 +268,7 @@ impl Sharecart {
   /// sc.misc[1] = 540;
   /// sc.misc[2] = 999;
   /// sc.misc[3] = ::std::u16::MAX;
-  /// sc.player_name = "Fearless Concurrency".to_string();
+  /// sc.player_name = "Fearless Concurrency".into();
   /// let mut foo = true;
   /// for i in 0 .. 8 {
   ///   sc.switch[i] = foo;
@@ -94,8 +277,22 @@ impl Sharecart {
   /// assert_eq!(sc, Sharecart::from_str(sc.to_string()));
   /// ```
   pub fn from_str<S: AsRef<str>>(buf: S) -> Self {
-    let buf_str = buf.as_ref();
-    match ini::Ini::load_from_str(buf_str) {
+    Self::from_bytes(buf.as_ref().as_bytes())
+  }
+
+  /// Parses the raw bytes of an `o_o.ini` file into a `Sharecart` value.
+  ///
+  /// This is what `from_str` delegates to. It exists as its own entry point
+  /// because a save file's bytes aren't guaranteed to be valid UTF-8 as a
+  /// whole (some legacy writers drop raw single-byte text straight into
+  /// `PlayerName`), even though every other field is always plain ASCII.
+  /// Every other field is read from a lossy UTF-8 decode of `buf` (same as
+  /// `from_str` would see), but `PlayerName` is recovered byte-for-byte
+  /// straight from `buf`, independent of whether the rest of the file
+  /// happened to be valid UTF-8.
+  pub fn from_bytes(buf: &[u8]) -> Self {
+    let lossy = buf.to_str_lossy();
+    match ini::Ini::load_from_str(&lossy) {
       Ok(i) => match i.section(Some("Main")).or(i.section(Some("main"))) {
         Some(properties) => {
           let mut sc = Sharecart::default();
@@ -120,13 +317,6 @@ impl Sharecart {
               "misc3" => {
                 sc.misc[3] = v.parse::<u16>().unwrap_or(0);
               }
-              "playername" => {
-                let byte_vec: Vec<u8> = v.bytes().take(1024).collect();
-                sc.player_name = String::from_utf8_lossy(&byte_vec)
-                  .chars()
-                  .filter(|&c| c != '\u{0FFFD}' && c != '\r' && c != '\n')
-                  .collect();
-              }
               "switch0" => {
                 sc.switch[0] = v.to_lowercase() == "true";
               }
@@ -154,6 +344,9 @@ impl Sharecart {
               _ => {}
             }
           }
+          if let Some(raw) = extract_raw_player_name(buf) {
+            sc.player_name = truncate_player_name(raw, 1024);
+          }
           sc
         }
         None => Sharecart::default(),
@@ -162,6 +355,98 @@ impl Sharecart {
     }
   }
 
+  /// Like `from_str`, except `player_name` is decoded using the given
+  /// [`LossyPolicy`] instead of always keeping the raw bytes as-is.
+  ///
+  /// Returns an error if `policy` is [`LossyPolicy::Reject`] and the
+  /// `PlayerName` value contains any invalid UTF-8.
+  pub fn from_str_with<S: AsRef<str>>(buf: S, policy: LossyPolicy) -> Result<Self, InvalidPlayerNameBytes> {
+    Self::from_bytes_with(buf.as_ref().as_bytes(), policy)
+  }
+
+  /// Like `from_bytes`, except `player_name` is decoded using the given
+  /// [`LossyPolicy`] instead of always keeping the raw bytes as-is.
+  ///
+  /// Returns an error if `policy` is [`LossyPolicy::Reject`] and the
+  /// `PlayerName` value contains any invalid UTF-8.
+  pub fn from_bytes_with(buf: &[u8], policy: LossyPolicy) -> Result<Self, InvalidPlayerNameBytes> {
+    let mut sc = Self::from_bytes(buf);
+    sc.player_name = decode_player_name_with_policy(&sc.player_name, policy)?.into();
+    Ok(sc)
+  }
+
+  /// Parses `buf` the same way `from_str` does, but instead of silently
+  /// defaulting every problem away, reports every [`ParseIssue`] it ran
+  /// into along the way.
+  ///
+  /// Returns `Ok` with a clean `Sharecart` if (and only if) nothing was
+  /// wrong with `buf`. If anything was off, even something as minor as one
+  /// unrecognized key, you get `Err` with the full list of issues instead
+  /// of a value, so tooling that validates or migrates save files can tell
+  /// a perfectly healthy file apart from one that merely *parsed* without
+  /// panicking. Callers that want a best-effort `Sharecart` unconditionally
+  /// (warn-and-continue rather than abort-on-any-issue) should use
+  /// `from_str` instead.
+  ///
+  /// ```rust
+  /// use sharecart1000::{Sharecart, ParseIssue};
+  ///
+  /// assert_eq!(Sharecart::from_str_strict("[Main]"), Ok(Sharecart::default()));
+  ///
+  /// assert_eq!(
+  ///   Sharecart::from_str_strict("[Main]\nMapX=9999"),
+  ///   Err(vec![ParseIssue::MapCoordOutOfRange {
+  ///     field: "MapX",
+  ///     raw: "9999".to_string(),
+  ///     wrapped: 9999 % 1024,
+  ///   }])
+  /// );
+  /// ```
+  pub fn from_str_strict(buf: &str) -> Result<Self, Vec<ParseIssue>> {
+    let mut issues = Vec::new();
+
+    let ini = match ini::Ini::load_from_str(buf) {
+      Ok(ini) => ini,
+      Err(_) => return Err(vec![ParseIssue::IniParseFailed]),
+    };
+
+    let properties = match ini.section(Some("Main")).or(ini.section(Some("main"))) {
+      Some(properties) => properties,
+      None => return Err(vec![ParseIssue::MissingMainSection]),
+    };
+
+    let mut sc = Sharecart::default();
+    for (k, v) in properties.iter() {
+      let lower = k.to_lowercase();
+      match lower.as_ref() {
+        "mapx" => parse_map_coord(&mut sc.map_x, "MapX", v, &mut issues),
+        "mapy" => parse_map_coord(&mut sc.map_y, "MapY", v, &mut issues),
+        "misc0" => parse_u16_field(&mut sc.misc[0], "Misc0", v, &mut issues),
+        "misc1" => parse_u16_field(&mut sc.misc[1], "Misc1", v, &mut issues),
+        "misc2" => parse_u16_field(&mut sc.misc[2], "Misc2", v, &mut issues),
+        "misc3" => parse_u16_field(&mut sc.misc[3], "Misc3", v, &mut issues),
+        "playername" => {
+          let kept = truncate_player_name(v.as_bytes(), 1024);
+          if v.len() > 1024 {
+            issues.push(ParseIssue::NameTruncated { original_len: v.len(), kept_len: kept.len() });
+          }
+          sc.player_name = kept;
+        }
+        "switch0" => sc.switch[0] = v.to_lowercase() == "true",
+        "switch1" => sc.switch[1] = v.to_lowercase() == "true",
+        "switch2" => sc.switch[2] = v.to_lowercase() == "true",
+        "switch3" => sc.switch[3] = v.to_lowercase() == "true",
+        "switch4" => sc.switch[4] = v.to_lowercase() == "true",
+        "switch5" => sc.switch[5] = v.to_lowercase() == "true",
+        "switch6" => sc.switch[6] = v.to_lowercase() == "true",
+        "switch7" => sc.switch[7] = v.to_lowercase() == "true",
+        _ => issues.push(ParseIssue::UnknownKey(k.to_string())),
+      }
+    }
+
+    if issues.is_empty() { Ok(sc) } else { Err(issues) }
+  }
+
   /// Gives you a `String` that you can write into the `o_o.ini` file.
   ///
   /// The string includes the "[Main]" section tag and other proper `ini`
@@ -191,31 +476,100 @@ impl Sharecart {
   /// "#);
   /// ```
   pub fn to_string(&self) -> String {
-    // There's about 170 chars of just boilerplate, so we'll get more than the
+    let truncated = truncate_player_name(&self.player_name, 1023);
+    let name = decode_player_name_with_policy(&truncated, LossyPolicy::Strip).expect("LossyPolicy::Strip never errors");
+    String::from_utf8(self.render_bytes(name.as_bytes())).expect("every other field is ASCII and `name` is valid UTF-8")
+  }
+
+  /// Like `to_string`, except the `PlayerName` line is decoded using the
+  /// given [`LossyPolicy`] instead of always silently stripping invalid
+  /// UTF-8.
+  ///
+  /// Returns an error if `policy` is [`LossyPolicy::Reject`] and
+  /// `player_name` contains any invalid UTF-8.
+  pub fn to_string_with(&self, policy: LossyPolicy) -> Result<String, InvalidPlayerNameBytes> {
+    let truncated = truncate_player_name(&self.player_name, 1023);
+    let name = decode_player_name_with_policy(&truncated, policy)?;
+    Ok(String::from_utf8(self.render_bytes(name.as_bytes())).expect("every other field is ASCII and `name` is valid UTF-8"))
+  }
+
+  /// Gives you the exact bytes to write into the `o_o.ini` file, preserving
+  /// any non-UTF-8 bytes in `player_name` verbatim instead of lossily
+  /// re-encoding them the way `to_string` has to.
+  ///
+  /// This is what `save_to_path` writes.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let truncated = truncate_player_name(&self.player_name, 1023);
+    self.render_bytes(&truncated)
+  }
+
+  /// Builds the full `o_o.ini` bytes, given the already-finalized
+  /// `PlayerName` bytes to write.
+  fn render_bytes(&self, player_name: &[u8]) -> Vec<u8> {
+    // There's about 170 bytes of just boilerplate, so we'll get more than the
     // default capacity here.
-    let mut s = String::with_capacity(200);
+    let mut s = Vec::with_capacity(200 + player_name.len());
 
-    s.push_str("[Main]\n");
-    s.push_str(&format!("MapX={}\n", self.map_x % 1024));
-    s.push_str(&format!("MapY={}\n", self.map_y % 1024));
+    s.extend_from_slice(b"[Main]\n");
+    s.extend_from_slice(format!("MapX={}\n", self.map_x % 1024).as_bytes());
+    s.extend_from_slice(format!("MapY={}\n", self.map_y % 1024).as_bytes());
     for i in 0..4 {
-      s.push_str(&format!("Misc{}={}\n", i, self.misc[i]));
+      s.extend_from_slice(format!("Misc{}={}\n", i, self.misc[i]).as_bytes());
     }
-    s.push_str("PlayerName=");
-    let byte_vec: Vec<u8> = self.player_name.bytes().take(1023).collect();
-    for ch in String::from_utf8_lossy(&byte_vec).chars() {
-      if ch == '\u{0FFFD}' || ch == '\r' || ch == '\n' {
-        continue;
-      }
-      s.push(ch);
-    }
-    s.push('\n');
+    s.extend_from_slice(b"PlayerName=");
+    s.extend_from_slice(player_name);
+    s.push(b'\n');
     for i in 0..8 {
-      s.push_str(&format!("Switch{}={}\n", i, if self.switch[i] { "TRUE" } else { "FALSE" }));
+      s.extend_from_slice(format!("Switch{}={}\n", i, if self.switch[i] { "TRUE" } else { "FALSE" }).as_bytes());
     }
 
     s
   }
+
+  /// Loads a `Sharecart` from the file at `path`.
+  ///
+  /// If the file doesn't exist, you get `Sharecart::default()` back, same as
+  /// loading an empty string. Any other I/O error (permissions, `path`
+  /// pointing at a directory, etc.) is passed along to you.
+  pub fn load_from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    match ::std::fs::read(path) {
+      Ok(buf) => Ok(Sharecart::from_bytes(&buf)),
+      Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Sharecart::default()),
+      Err(e) => Err(e),
+    }
+  }
+
+  /// Saves this `Sharecart` to the file at `path`, overwriting whatever was
+  /// there before.
+  ///
+  /// This writes out exactly the bytes that [`to_bytes`](#method.to_bytes)
+  /// produces, `\n` line endings and all. For a `player_name` that's valid
+  /// UTF-8 this is the same as `to_string()`'s bytes; unlike `to_string()`,
+  /// it also preserves a non-UTF-8 `player_name` byte-for-byte rather than
+  /// stripping it, so legacy single-byte names round-trip through disk
+  /// intact.
+  pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+    ::std::fs::write(path, self.to_bytes())
+  }
+
+  /// Gives you a `&str`-like view of [`player_name`](#structfield.player_name),
+  /// lossily re-interpreting any invalid UTF-8 as `U+FFFD`.
+  ///
+  /// This is a convenience for callers who want the UTF-8 string experience
+  /// but are reading a `Sharecart` that might have come from a game that
+  /// wrote raw, non-UTF-8 bytes into `PlayerName`.
+  pub fn player_name_lossy(&self) -> Cow<'_, str> {
+    self.player_name.to_str_lossy()
+  }
+
+  /// Like `player_name_lossy`, except the given [`LossyPolicy`] controls
+  /// how invalid UTF-8 is handled instead of always substituting `U+FFFD`.
+  ///
+  /// Returns an error if `policy` is [`LossyPolicy::Reject`] and
+  /// `player_name` contains any invalid UTF-8.
+  pub fn player_name_lossy_with(&self, policy: LossyPolicy) -> Result<String, InvalidPlayerNameBytes> {
+    decode_player_name_with_policy(&self.player_name, policy)
+  }
 }
 
 #[test]
@@ -234,10 +588,84 @@ fn test_sharecart_10bit_safe() {
 fn test_sharecart_player_name_safe() {
   let mut sc = Sharecart::default();
 
-  sc.player_name = "\r\n".to_string();
+  sc.player_name = "\r\n".into();
   assert_eq!(Sharecart::default(), Sharecart::from_str(sc.to_string()));
 
-  sc.player_name = "x".repeat(2_000);
+  sc.player_name = "x".repeat(2_000).into();
   let round_trip = Sharecart::from_str(sc.to_string());
   assert_eq!(round_trip.player_name.len(), 1023);
 }
+
+#[test]
+fn test_sharecart_player_name_grapheme_safe() {
+  // "e" followed by a combining acute accent (`U+0301`) is one grapheme
+  // cluster spanning 3 bytes (1 for 'e', 2 for the combining mark). After a
+  // one-byte "x" prefix and enough repeats, the raw byte count crosses the
+  // 1023 byte budget right in the middle of a cluster (1 + 341 * 3 = 1024),
+  // so a byte-oriented truncation would split the cluster. Grapheme-aware
+  // truncation must always keep or drop the whole cluster.
+  let cluster = "e\u{0301}";
+  assert_eq!(cluster.len(), 3);
+
+  let mut sc = Sharecart::default();
+  sc.player_name = format!("x{}", cluster.repeat(400)).into();
+  let round_trip = Sharecart::from_str(sc.to_string());
+
+  assert_eq!(round_trip.player_name.len(), 1021);
+  assert_eq!(round_trip.player_name, format!("x{}", cluster.repeat(340)));
+}
+
+#[test]
+fn test_sharecart_from_bytes_with_lossy_policy() {
+  // A Latin-1-style "Café" with the 'é' written as the single invalid byte
+  // 0xE9, the way a legacy save writer that isn't UTF-8-aware might produce.
+  let buf: &[u8] = b"[Main]\nPlayerName=Caf\xE9\n";
+
+  let raw = Sharecart::from_bytes(buf);
+  assert_eq!(raw.player_name, b"Caf\xE9".as_bstr());
+
+  let stripped = Sharecart::from_bytes_with(buf, LossyPolicy::Strip).unwrap();
+  assert_eq!(stripped.player_name, "Caf");
+
+  let replaced = Sharecart::from_bytes_with(buf, LossyPolicy::Replace).unwrap();
+  assert_eq!(replaced.player_name, "Caf\u{FFFD}");
+
+  assert_eq!(Sharecart::from_bytes_with(buf, LossyPolicy::Reject), Err(InvalidPlayerNameBytes));
+
+  // Valid UTF-8 input is unaffected by the policy at all.
+  let ascii: &[u8] = b"[Main]\nPlayerName=Cafe\n";
+  assert_eq!(Sharecart::from_bytes_with(ascii, LossyPolicy::Reject).unwrap().player_name, "Cafe");
+}
+
+#[test]
+fn test_sharecart_from_bytes_trims_whitespace_around_key_and_value() {
+  // `ini` trims whitespace around both the key and the value for every
+  // other field; the raw byte-level PlayerName extractor has to match that
+  // or spaced-out lines silently lose their name.
+  let spaced: &[u8] = b"[Main]\nPlayerName = Bob \nMapX = 5\n";
+  let sc = Sharecart::from_bytes(spaced);
+  assert_eq!(sc.player_name, "Bob");
+  assert_eq!(sc.map_x, 5);
+}
+
+#[test]
+fn test_sharecart_from_str_strict_issues() {
+  assert_eq!(
+    Sharecart::from_str_strict("[Main]\nMapX=9999\nMisc0=nope\nWeird=1"),
+    Err(vec![
+      ParseIssue::MapCoordOutOfRange { field: "MapX", raw: "9999".to_string(), wrapped: 9999 % 1024 },
+      ParseIssue::IntParseFailed { field: "Misc0", raw: "nope".to_string() },
+      ParseIssue::UnknownKey("Weird".to_string()),
+    ])
+  );
+
+  assert_eq!(Sharecart::from_str_strict("not ini at all [[["), Err(vec![ParseIssue::IniParseFailed]));
+
+  assert_eq!(Sharecart::from_str_strict("[Other]\nMapX=5"), Err(vec![ParseIssue::MissingMainSection]));
+
+  assert_eq!(Sharecart::from_str_strict("[Main]\nMapX=73"), Ok({
+    let mut sc = Sharecart::default();
+    sc.map_x = 73;
+    sc
+  }));
+}